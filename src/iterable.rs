@@ -1,19 +1,60 @@
+use std::collections::VecDeque;
+
 use pyo3::prelude::*;
 
+use crate::hashedany::HashedAny;
 
+// A lazy iterator over the transitive neighbours of a node.
+//
+// Rather than materialising the whole reachable set up front, we keep a
+// breadth-first `frontier` and a `visited` bitset and expand one node per
+// `__next__` call. The adjacency (`children` for ancestors, `parents` for
+// descendants) and the id -> object mapping are cloned in so the iterator
+// stays valid even if the sorter it came from is later mutated or dropped.
 #[pyclass]
-struct Iter {
-    src: Py<T>,
-    cb: impl FnMut(Py<T>) -> Option<PyObject>,
+pub(crate) struct Iter {
+    id2node: Vec<HashedAny>,
+    adjacency: Vec<Vec<usize>>,
+    frontier: VecDeque<usize>,
+    visited: Vec<bool>,
 }
 
+impl Iter {
+    // Seed the frontier with the immediate neighbours of `start`. The start
+    // node itself is marked visited but never yielded.
+    pub(crate) fn new(id2node: Vec<HashedAny>, adjacency: Vec<Vec<usize>>, start: usize) -> Self {
+        let mut visited = vec![false; adjacency.len()];
+        let mut frontier = VecDeque::new();
+        visited[start] = true;
+        for &neighbor in adjacency.get(start).unwrap() {
+            if !visited[neighbor] {
+                visited[neighbor] = true;
+                frontier.push_back(neighbor);
+            }
+        }
+        Iter {
+            id2node,
+            adjacency,
+            frontier,
+            visited,
+        }
+    }
+}
 
-#[pyproto]
-impl PyIterProtocol for Iter {
-    fn __iter__(self: PyRef<Self>) -> Py<Iter> {
-        self
+#[pymethods]
+impl Iter {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
     }
     fn __next__(mut slf: PyRefMut<Self>) -> Option<PyObject> {
-        self.cb(self.src)
+        let node = slf.frontier.pop_front()?;
+        let neighbors = slf.adjacency.get(node).unwrap().clone();
+        for neighbor in neighbors {
+            if !slf.visited[neighbor] {
+                slf.visited[neighbor] = true;
+                slf.frontier.push_back(neighbor);
+            }
+        }
+        Some(slf.id2node.get(node).unwrap().0.clone())
     }
 }
@@ -1,5 +1,4 @@
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::fmt;
 
@@ -8,11 +7,18 @@ use pyo3::create_exception;
 use pyo3::exceptions;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::types::PyDict;
+use pyo3::types::PyList;
+use pyo3::types::PySet;
 use pyo3::types::PyTuple;
+use pyo3::types::PyType;
 use pyo3::{PyAny, Python};
 
 mod hashedany;
+mod iterable;
 use crate::hashedany::HashedAny;
+use crate::iterable::Iter;
 
 create_exception!(graphlib2, CycleError, exceptions::PyValueError);
 
@@ -23,6 +29,27 @@ enum NodeState {
     Done,
 }
 
+impl NodeState {
+    fn as_u8(self) -> u8 {
+        match self {
+            NodeState::Active => 0,
+            NodeState::Ready => 1,
+            NodeState::Done => 2,
+        }
+    }
+    fn from_u8(byte: u8) -> PyResult<NodeState> {
+        match byte {
+            0 => Ok(NodeState::Active),
+            1 => Ok(NodeState::Ready),
+            2 => Ok(NodeState::Done),
+            other => Err(PyValueError::new_err(format!(
+                "invalid node state byte {} in snapshot",
+                other
+            ))),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct NodeInfo {
     state: NodeState,
@@ -67,54 +94,84 @@ impl UnpreparedState {
         }
         Ok(())
     }
-    fn find_cycle(&self) -> Option<Vec<usize>> {
-        // Do a DFS with backtracking to find any cycles
-        let mut seen: HashSet<usize> = HashSet::new();
-        let mut stack = Vec::new();
-        let mut itstack = Vec::new();
-        let mut node2stackid = HashMap::new();
-        let mut node: usize;
-
-        for &n in self.node2id.values() {
-            node = n;
-            if seen.contains(&node) {
+    // Tarjan's strongly connected components over the `parents` adjacency.
+    //
+    // The recursion is maintained on an explicit work stack so that a deep or
+    // wide graph can never overflow the native (and thus the Python) call
+    // stack. Each work-stack entry carries the node being explored and a cursor
+    // into its successor list so we can resume where we left off after a child
+    // has been fully visited. Components are returned in the order Tarjan emits
+    // them (reverse topological order of the condensation).
+    fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let n = self.parents.len();
+        let mut index: Vec<Option<usize>> = vec![None; n];
+        let mut lowlink: Vec<usize> = vec![0; n];
+        let mut on_stack: Vec<bool> = vec![false; n];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut work: Vec<(usize, usize)> = Vec::new();
+        let mut counter: usize = 0;
+        let mut components: Vec<Vec<usize>> = Vec::new();
+
+        for start in 0..n {
+            if index[start].is_some() {
                 continue;
             }
-            'outer: loop {
-                if seen.contains(&node) {
-                    // If this node is in the current stack, we have a cycle
-                    if node2stackid.contains_key(&node) {
-                        let start_id = node2stackid.get(&node).unwrap();
-                        let mut res = stack[*start_id..].to_vec();
-                        res.push(node);
-                        return Some(res);
-                    }
-                } else {
-                    seen.insert(node);
-                    itstack.push(self.parents.get(node).unwrap().iter());
-                    node2stackid.insert(node, stack.len());
-                    stack.push(node);
+            work.push((start, 0));
+            while let Some(&(v, cursor)) = work.last() {
+                if cursor == 0 {
+                    // First time we touch `v`: assign its index/lowlink and
+                    // push it onto the SCC stack.
+                    index[v] = Some(counter);
+                    lowlink[v] = counter;
+                    counter += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
                 }
-                // Backtrack to the topmost stack entry with at least 1 parent
-                loop {
-                    if stack.is_empty() {
-                        break 'outer;
+                let successors = self.parents.get(v).unwrap();
+                if cursor < successors.len() {
+                    // Advance the cursor before descending so we resume at the
+                    // next successor once the child returns.
+                    work.last_mut().unwrap().1 += 1;
+                    let w = successors[cursor];
+                    if index[w].is_none() {
+                        work.push((w, 0));
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index[w].unwrap());
                     }
-                    match itstack.last_mut().unwrap().next() {
-                        Some(parent) => {
-                            node = *parent;
-                            break;
-                        }
-                        None => {
-                            node2stackid.remove(&stack.pop().unwrap());
-                            itstack.pop();
-                            continue;
+                } else {
+                    // Every successor of `v` has been explored.
+                    if lowlink[v] == index[v].unwrap() {
+                        let mut component = Vec::new();
+                        loop {
+                            let w = stack.pop().unwrap();
+                            on_stack[w] = false;
+                            component.push(w);
+                            if w == v {
+                                break;
+                            }
                         }
+                        components.push(component);
+                    }
+                    work.pop();
+                    if let Some(&(parent, _)) = work.last() {
+                        lowlink[parent] = lowlink[parent].min(lowlink[v]);
                     }
                 }
             }
         }
-        None
+        components
+    }
+    // A strongly connected component is a cycle if it has more than one member,
+    // or a single node that depends on itself (a self-loop).
+    fn cycles(&self) -> Vec<Vec<usize>> {
+        self.strongly_connected_components()
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1
+                    || (component.len() == 1
+                        && self.parents.get(component[0]).unwrap().contains(&component[0]))
+            })
+            .collect()
     }
 }
 
@@ -124,6 +181,9 @@ struct SolvedDAG {
     id2node: Vec<HashedAny>,
     node2id: HashMap<HashedAny, usize, BuildNoHashHasher<isize>>,
     parents: Vec<Vec<usize>>,
+    // Reverse of `parents`, built once at prepare() time so reachability
+    // queries can walk either direction without rebuilding the adjacency.
+    children: Vec<Vec<usize>>,
 }
 
 #[derive(Clone)]
@@ -224,18 +284,34 @@ impl TopologicalSorter {
             State::Unprepared(state) => state,
         };
         let mut ready_nodes = VecDeque::with_capacity(state.node2id.len());
-        if let Some(cycle) = state.find_cycle() {
-            let nodes_in_cyle: Vec<HashedAny> = cycle
-                .into_iter()
-                .map(|n| state.id2node.get(n).unwrap().clone())
-                .collect();
-            let items_str: PyResult<Vec<String>> = nodes_in_cyle
-                .iter()
-                .map(|n| hashed_node_to_str(n))
-                .collect();
-            let py_items: Vec<Py<PyAny>> = nodes_in_cyle.iter().map(|n| n.0.clone()).collect();
+        let cycles = state.cycles();
+        if !cycles.is_empty() {
+            // Render one "[a -> b -> a]" chunk per cycle and hand back the nodes
+            // of every cycle so callers get a complete diagnostic rather than a
+            // single arbitrary back-edge.
+            let mut chunks: Vec<String> = Vec::with_capacity(cycles.len());
+            // Keep the payload a flat list of nodes (the concatenation of every
+            // cycle), matching the stdlib `graphlib.CycleError` shape where
+            // `err.args[1]` is a single node list; the message names each cycle.
+            let mut py_items: Vec<Py<PyAny>> = Vec::new();
+            for cycle in cycles.into_iter() {
+                let nodes_in_cycle: Vec<HashedAny> = cycle
+                    .into_iter()
+                    .map(|n| state.id2node.get(n).unwrap().clone())
+                    .collect();
+                let mut items_str: Vec<String> = nodes_in_cycle
+                    .iter()
+                    .map(hashed_node_to_str)
+                    .collect::<PyResult<Vec<String>>>()?;
+                // Repeat the first node to visually close the loop.
+                if let Some(first) = items_str.first().cloned() {
+                    items_str.push(first);
+                }
+                chunks.push(format!("[{}]", items_str.join(" -> ")));
+                py_items.extend(nodes_in_cycle.iter().map(|n| n.0.clone()));
+            }
             return Err(CycleError::new_err((
-                format!("Nodes are in a cycle [{}]", items_str?.join(" -> ")),
+                format!("Nodes are in a cycle {}", chunks.join(", ")),
                 py_items,
             )));
         }
@@ -245,11 +321,18 @@ impl TopologicalSorter {
                 nodeinfo.state = NodeState::Ready;
             }
         }
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); state.parents.len()];
+        for (node, parents) in state.parents.iter().enumerate() {
+            for &parent in parents {
+                children.get_mut(parent).unwrap().push(node);
+            }
+        }
         self.state = State::Prepared(PreparedState {
             dag: SolvedDAG {
                 id2node: state.id2node.clone(),
                 node2id: state.node2id.clone(),
                 parents: state.parents.clone(),
+                children,
             },
             ready_nodes,
             id2nodeinfo: state.id2nodeinfo.clone(),
@@ -329,16 +412,403 @@ impl TopologicalSorter {
         };
         Ok(state.get_ready(py))
     }
+    /// Returns a lazy iterator over the transitive predecessors of `node`
+    fn ancestors(&self, py: Python, node: HashedAny) -> PyResult<Py<Iter>> {
+        let dag = self.solved_dag()?;
+        let node_id = resolve_node(dag, &node)?;
+        // Predecessors (dependencies) are held in `children`.
+        Py::new(
+            py,
+            Iter::new(dag.id2node.clone(), dag.children.clone(), node_id),
+        )
+    }
+    /// Returns a lazy iterator over the transitive successors of `node`
+    fn descendants(&self, py: Python, node: HashedAny) -> PyResult<Py<Iter>> {
+        let dag = self.solved_dag()?;
+        let node_id = resolve_node(dag, &node)?;
+        // Successors (dependents) are held in `parents`.
+        Py::new(
+            py,
+            Iter::new(dag.id2node.clone(), dag.parents.clone(), node_id),
+        )
+    }
+    /// Returns a mapping from each node reachable from `root` to its immediate
+    /// dominator in the dependency DAG
+    ///
+    /// Edges are taken to flow from a dependency to its dependents, so the
+    /// immediate dominator of a node is the single node that, if delayed, blocks
+    /// that node's whole subtree. Computed with the Cooper-Harvey-Kennedy
+    /// iterative algorithm over a reverse-postorder numbering.
+    fn dominators<'py>(&self, py: Python<'py>, root: HashedAny) -> PyResult<&'py PyDict> {
+        let dag = self.solved_dag()?;
+        let root_id = resolve_node(dag, &root)?;
+        let n = dag.id2node.len();
+
+        // Postorder (and thus the set of nodes reachable from `root`) via an
+        // explicit-stack DFS over the dependency -> dependent edges.
+        let mut postorder: Vec<usize> = Vec::new();
+        let mut visited = vec![false; n];
+        let mut stack: Vec<(usize, usize)> = vec![(root_id, 0)];
+        visited[root_id] = true;
+        while let Some(&(v, cursor)) = stack.last() {
+            let successors = dag.parents.get(v).unwrap();
+            if cursor < successors.len() {
+                stack.last_mut().unwrap().1 += 1;
+                let w = successors[cursor];
+                if !visited[w] {
+                    visited[w] = true;
+                    stack.push((w, 0));
+                }
+            } else {
+                postorder.push(v);
+                stack.pop();
+            }
+        }
+        let mut postorder_number: Vec<Option<usize>> = vec![None; n];
+        for (number, &node) in postorder.iter().enumerate() {
+            postorder_number[node] = Some(number);
+        }
+
+        let mut idom: Vec<Option<usize>> = vec![None; n];
+        idom[root_id] = Some(root_id);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            // Reverse postorder, skipping the root.
+            for &node in postorder.iter().rev() {
+                if node == root_id {
+                    continue;
+                }
+                let mut new_idom: Option<usize> = None;
+                for &pred in dag.children.get(node).unwrap() {
+                    // Only predecessors reachable from the root participate, and
+                    // only once they have a provisional idom.
+                    if postorder_number[pred].is_none() || idom[pred].is_none() {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => pred,
+                        Some(current) => intersect(pred, current, &idom, &postorder_number),
+                    });
+                }
+                if let Some(candidate) = new_idom {
+                    if idom[node] != Some(candidate) {
+                        idom[node] = Some(candidate);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        let result = PyDict::new(py);
+        for &node in postorder.iter() {
+            if let Some(dominator) = idom[node] {
+                result.set_item(
+                    dag.id2node.get(node).unwrap().0.as_ref(py),
+                    dag.id2node.get(dominator).unwrap().0.as_ref(py),
+                )?;
+            }
+        }
+        Ok(result)
+    }
+    /// Returns the set of nodes still needing processing given `done_nodes`
+    ///
+    /// The scheduler is left untouched. Completing a node implies all of its
+    /// transitive dependencies were completed too, so we mark the `done_nodes`
+    /// and then walk `children` (the dependency adjacency) from each of them to
+    /// absorb their upstream dependencies into the done set; everything not so
+    /// covered is the remaining work. A caller resuming a partially-completed
+    /// pipeline can use this to compute the outstanding delta without replaying
+    /// get_ready()/done() loops.
+    fn remaining_after<'py>(
+        &self,
+        py: Python<'py>,
+        done_nodes: Vec<HashedAny>,
+    ) -> PyResult<&'py PySet> {
+        let dag = self.solved_dag()?;
+        let n = dag.id2node.len();
+        let mut done = vec![false; n];
+        let mut frontier: VecDeque<usize> = VecDeque::new();
+        for node in done_nodes.iter() {
+            let id = resolve_node(dag, node)?;
+            if !done[id] {
+                done[id] = true;
+                frontier.push_back(id);
+            }
+        }
+
+        // Transitively mark the dependencies of every done node as done.
+        while let Some(node) = frontier.pop_front() {
+            for &dependency in dag.children.get(node).unwrap() {
+                if !done[dependency] {
+                    done[dependency] = true;
+                    frontier.push_back(dependency);
+                }
+            }
+        }
+
+        let result = PySet::empty(py)?;
+        for node in 0..n {
+            if !done[node] {
+                result.add(dag.id2node.get(node).unwrap().0.as_ref(py))?;
+            }
+        }
+        Ok(result)
+    }
+    /// Serialize a prepared graph to a compact packed buffer
+    ///
+    /// The layout is a small fixed header followed by the `parents` adjacency in
+    /// CSR form (an offsets array plus a flat edge array), the per-node info,
+    /// the scheduler counters, and finally a pickle of the node objects keyed by
+    /// id. `loads()` can rehydrate the whole sorter from this without re-running
+    /// cycle detection.
+    fn dumps<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let state = match &self.state {
+            State::Prepared(state) => state,
+            State::Unprepared(_) => {
+                return Err(exceptions::PyValueError::new_err(
+                    "prepare() must be called before dumps()",
+                ))
+            }
+        };
+        let dag = &state.dag;
+        let node_count = dag.id2node.len();
+        let edge_count: usize = dag.parents.iter().map(|p| p.len()).sum();
+
+        let mut buf: Vec<u8> = Vec::new();
+        push_u32(&mut buf, SNAPSHOT_MAGIC);
+        push_u32(&mut buf, SNAPSHOT_VERSION);
+        push_u32(&mut buf, node_count as u32);
+        push_u32(&mut buf, edge_count as u32);
+
+        // CSR offsets (node_count + 1 entries) and flat edge array for `parents`.
+        let mut offset: u32 = 0;
+        push_u32(&mut buf, offset);
+        for parents in dag.parents.iter() {
+            offset += parents.len() as u32;
+            push_u32(&mut buf, offset);
+        }
+        for parents in dag.parents.iter() {
+            for &parent in parents {
+                push_u32(&mut buf, parent as u32);
+            }
+        }
+
+        // Per-node state byte followed by the predecessor counts.
+        for info in state.id2nodeinfo.iter() {
+            buf.push(info.state.as_u8());
+        }
+        for info in state.id2nodeinfo.iter() {
+            push_u32(&mut buf, info.npredecessors as u32);
+        }
+
+        // The ready queue is explicit because a node stays `Ready` after being
+        // passed out, so it cannot be reconstructed from the state bytes alone.
+        push_u32(&mut buf, state.ready_nodes.len() as u32);
+        for &node in state.ready_nodes.iter() {
+            push_u32(&mut buf, node as u32);
+        }
+        push_u32(&mut buf, state.n_passed_out as u32);
+        push_u32(&mut buf, state.n_finished as u32);
+
+        // Pickle the node objects in id order.
+        let pickle = py.import("pickle")?;
+        let objects: Vec<&PyAny> = dag.id2node.iter().map(|n| n.0.as_ref(py)).collect();
+        let blob = pickle
+            .getattr("dumps")?
+            .call1((PyList::new(py, objects),))?
+            .downcast::<PyBytes>()?;
+        let blob = blob.as_bytes();
+        push_u32(&mut buf, blob.len() as u32);
+        buf.extend_from_slice(blob);
+
+        Ok(PyBytes::new(py, &buf))
+    }
+    /// Reconstruct a prepared graph from a buffer produced by `dumps()`
+    #[classmethod]
+    fn loads(_cls: &PyType, py: Python, data: &PyBytes) -> PyResult<TopologicalSorter> {
+        let bytes = data.as_bytes();
+        let mut cursor: usize = 0;
+
+        let magic = read_u32(bytes, &mut cursor)?;
+        if magic != SNAPSHOT_MAGIC {
+            return Err(PyValueError::new_err("not a graphlib2 snapshot"));
+        }
+        let version = read_u32(bytes, &mut cursor)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(PyValueError::new_err(format!(
+                "unsupported snapshot version {}",
+                version
+            )));
+        }
+        let node_count = read_u32(bytes, &mut cursor)? as usize;
+        let edge_count = read_u32(bytes, &mut cursor)? as usize;
+
+        // CSR offsets + edges -> per-node `parents` lists.
+        let mut offsets: Vec<usize> = Vec::with_capacity(node_count + 1);
+        for _ in 0..=node_count {
+            offsets.push(read_u32(bytes, &mut cursor)? as usize);
+        }
+        let mut edges: Vec<usize> = Vec::with_capacity(edge_count);
+        for _ in 0..edge_count {
+            edges.push(read_u32(bytes, &mut cursor)? as usize);
+        }
+        let mut parents: Vec<Vec<usize>> = Vec::with_capacity(node_count);
+        for node in 0..node_count {
+            parents.push(edges[offsets[node]..offsets[node + 1]].to_vec());
+        }
+
+        // Per-node state bytes then predecessor counts.
+        let mut states: Vec<NodeState> = Vec::with_capacity(node_count);
+        for _ in 0..node_count {
+            states.push(NodeState::from_u8(read_u8(bytes, &mut cursor)?)?);
+        }
+        let mut id2nodeinfo: Vec<NodeInfo> = Vec::with_capacity(node_count);
+        for state in states.into_iter() {
+            id2nodeinfo.push(NodeInfo {
+                state,
+                npredecessors: read_u32(bytes, &mut cursor)? as usize,
+            });
+        }
+
+        let ready_len = read_u32(bytes, &mut cursor)? as usize;
+        let mut ready_nodes: VecDeque<usize> = VecDeque::with_capacity(ready_len);
+        for _ in 0..ready_len {
+            ready_nodes.push_back(read_u32(bytes, &mut cursor)? as usize);
+        }
+        let n_passed_out = read_u32(bytes, &mut cursor)? as usize;
+        let n_finished = read_u32(bytes, &mut cursor)? as usize;
+
+        // Unpickle the node objects and rebuild node2id by re-hashing them.
+        let blob_len = read_u32(bytes, &mut cursor)? as usize;
+        if cursor + blob_len > bytes.len() {
+            return Err(PyValueError::new_err("truncated snapshot"));
+        }
+        let blob = PyBytes::new(py, &bytes[cursor..cursor + blob_len]);
+        let pickle = py.import("pickle")?;
+        let objects = pickle.getattr("loads")?.call1((blob,))?;
+        let objects = objects.downcast::<PyList>()?;
+        if objects.len() != node_count {
+            return Err(PyValueError::new_err("snapshot node count mismatch"));
+        }
+        let mut id2node: Vec<HashedAny> = Vec::with_capacity(node_count);
+        let mut node2id: HashMap<HashedAny, usize, BuildNoHashHasher<isize>> =
+            HashMap::with_capacity_and_hasher(node_count, BuildNoHashHasher::default());
+        for (id, obj) in objects.iter().enumerate() {
+            let node = HashedAny::extract(obj)?;
+            node2id.insert(node.clone(), id);
+            id2node.push(node);
+        }
+
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        for (node, parents) in parents.iter().enumerate() {
+            for &parent in parents {
+                children.get_mut(parent).unwrap().push(node);
+            }
+        }
+
+        Ok(TopologicalSorter {
+            state: State::Prepared(PreparedState {
+                dag: SolvedDAG {
+                    id2node,
+                    node2id,
+                    parents,
+                    children,
+                },
+                ready_nodes,
+                id2nodeinfo,
+                n_passed_out,
+                n_finished,
+            }),
+        })
+    }
+}
+
+impl TopologicalSorter {
+    // Borrow the prepared DAG, erroring if prepare() has not been called yet.
+    fn solved_dag(&self) -> PyResult<&SolvedDAG> {
+        match &self.state {
+            State::Prepared(state) => Ok(&state.dag),
+            State::Unprepared(_) => Err(exceptions::PyValueError::new_err(
+                "prepare() must be called first",
+            )),
+        }
+    }
 }
 
 #[pymodule]
 fn _graphlib2(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<TopologicalSorter>()?;
+    m.add_class::<Iter>()?;
     m.add("CycleError", _py.get_type::<CycleError>())?;
     Ok(())
 }
 
+// Snapshot format constants. Read as a big-endian u32 the magic is "GL2" plus a
+// zero byte; like every field it is written little-endian. The layout version
+// is a separate u32 stored right after the magic.
+const SNAPSHOT_MAGIC: u32 = 0x47_4C_32_00;
+const SNAPSHOT_VERSION: u32 = 1;
+
+// Little-endian (de)serialization helpers for the packed snapshot buffer.
+fn push_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_le_bytes());
+}
+
+fn read_u32(bytes: &[u8], cursor: &mut usize) -> PyResult<u32> {
+    if *cursor + 4 > bytes.len() {
+        return Err(PyValueError::new_err("truncated snapshot"));
+    }
+    let value = u32::from_le_bytes([
+        bytes[*cursor],
+        bytes[*cursor + 1],
+        bytes[*cursor + 2],
+        bytes[*cursor + 3],
+    ]);
+    *cursor += 4;
+    Ok(value)
+}
+
+fn read_u8(bytes: &[u8], cursor: &mut usize) -> PyResult<u8> {
+    if *cursor >= bytes.len() {
+        return Err(PyValueError::new_err("truncated snapshot"));
+    }
+    let value = bytes[*cursor];
+    *cursor += 1;
+    Ok(value)
+}
+
+// Walk two fingers up the provisional dominator tree until they meet, climbing
+// whichever finger has the smaller postorder number (i.e. sits lower in the
+// tree). Used by `dominators()` to combine two predecessors' dominators.
+fn intersect(
+    mut finger1: usize,
+    mut finger2: usize,
+    idom: &[Option<usize>],
+    postorder_number: &[Option<usize>],
+) -> usize {
+    while finger1 != finger2 {
+        while postorder_number[finger1] < postorder_number[finger2] {
+            finger1 = idom[finger1].unwrap();
+        }
+        while postorder_number[finger2] < postorder_number[finger1] {
+            finger2 = idom[finger2].unwrap();
+        }
+    }
+    finger1
+}
+
 // Misc helper methods
+fn resolve_node(dag: &SolvedDAG, node: &HashedAny) -> PyResult<usize> {
+    match dag.node2id.get(node) {
+        Some(&id) => Ok(id),
+        None => Err(PyValueError::new_err(format!(
+            "node {} was not added using add()",
+            hashed_node_to_str(node)?
+        ))),
+    }
+}
+
 fn hashed_node_to_str(node: &HashedAny) -> PyResult<String> {
     Python::with_gil(|py| -> PyResult<String> {
         Ok(node.0.as_ref(py).repr()?.to_str()?.to_string())